@@ -0,0 +1,57 @@
+use std::time::Duration;
+
+use bevy::prelude::*;
+
+use crate::{Animations, PlayerState};
+
+/// How long a cross-fade between two animation clips takes.
+const BLEND_DURATION: Duration = Duration::from_millis(200);
+
+/// How quickly `locomotion_weight` chases its target each frame; higher
+/// settles faster.
+const LOCOMOTION_SMOOTHING: f32 = 6.0;
+
+/// Drives one character's `AnimationPlayer` from its `PlayerState`,
+/// cross-fading between clips instead of hard-cutting.
+#[derive(Component, Default)]
+pub struct AnimationController {
+    last_state: Option<PlayerState>,
+    // 0.0 at a standstill, 1.0 at full running speed.
+    pub locomotion_weight: f32,
+}
+
+fn clip_for_state(animations: &Animations, state: PlayerState) -> (AnimationNodeIndex, f32, bool) {
+    match state {
+        PlayerState::Idle => (animations.idle, 1.0, true),
+        PlayerState::Running => (animations.run, 1.5, true),
+        PlayerState::Punching => (animations.punch, 1.0, false),
+        PlayerState::Jumping => (animations.jump, 1.0, false),
+        PlayerState::Dead => (animations.fall, 1.0, false),
+    }
+}
+
+impl AnimationController {
+    /// Cross-fades `player` to the clip for `state` and advances
+    /// `locomotion_weight` toward that state's target.
+    pub fn apply(
+        &mut self,
+        player: &mut AnimationPlayer,
+        animations: &Animations,
+        state: PlayerState,
+        delta_seconds: f32,
+    ) {
+        if self.last_state != Some(state) {
+            let (node, speed, repeat) = clip_for_state(animations, state);
+            let active = player.play_with_transition(node, BLEND_DURATION);
+            active.set_speed(speed);
+            if repeat {
+                active.repeat();
+            }
+            self.last_state = Some(state);
+        }
+
+        let target_weight = if state == PlayerState::Running { 1.0 } else { 0.0 };
+        let t = (delta_seconds * LOCOMOTION_SMOOTHING).min(1.0);
+        self.locomotion_weight += (target_weight - self.locomotion_weight) * t;
+    }
+}
@@ -0,0 +1,71 @@
+use bevy::audio::Volume;
+use bevy::prelude::*;
+
+use crate::AudioAssets;
+
+/// Sound cues gameplay systems fire instead of spawning sounds themselves.
+#[derive(Event, Clone, Copy)]
+pub enum GameAudio {
+    Punch { position: Vec3 },
+    Jump { position: Vec3 },
+    Hit { position: Vec3 },
+    Land { position: Vec3 },
+    Footstep { position: Vec3 },
+}
+
+impl GameAudio {
+    fn position(&self) -> Vec3 {
+        match *self {
+            GameAudio::Punch { position }
+            | GameAudio::Jump { position }
+            | GameAudio::Hit { position }
+            | GameAudio::Land { position }
+            | GameAudio::Footstep { position } => position,
+        }
+    }
+}
+
+/// How often a `Running` character's feet should land, in seconds.
+pub const FOOTSTEP_INTERVAL: f32 = 0.35;
+
+/// Ticks while its owner is `Running`; fires a `Footstep` cue each time it
+/// finishes.
+#[derive(Component)]
+pub struct Footsteps {
+    pub timer: Timer,
+}
+
+impl Default for Footsteps {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(FOOTSTEP_INTERVAL, TimerMode::Repeating),
+        }
+    }
+}
+
+/// Consumes `GameAudio` cues and spawns the matching spatial sound.
+pub fn audio_system(
+    mut commands: Commands,
+    mut events: EventReader<GameAudio>,
+    audio_assets: Res<AudioAssets>,
+) {
+    for event in events.read() {
+        let (source, volume) = match event {
+            GameAudio::Punch { .. } => (audio_assets.punch.clone(), 1.0),
+            GameAudio::Jump { .. } => (audio_assets.jump.clone(), 0.6),
+            GameAudio::Hit { .. } => (audio_assets.hit.clone(), 1.0),
+            GameAudio::Land { .. } => (audio_assets.land.clone(), 0.5),
+            GameAudio::Footstep { .. } => (audio_assets.footstep.clone(), 0.4),
+        };
+
+        commands.spawn((
+            AudioBundle {
+                source,
+                settings: PlaybackSettings::DESPAWN
+                    .with_spatial(true)
+                    .with_volume(Volume::new(volume)),
+            },
+            SpatialBundle::from_transform(Transform::from_translation(event.position())),
+        ));
+    }
+}
@@ -0,0 +1,217 @@
+use bevy::prelude::*;
+use bevy_xpbd_3d::prelude::*;
+
+use crate::audio::GameAudio;
+use crate::{Enemy, Player, PlayerState};
+
+pub const PLAYER_MAX_HEALTH: f32 = 100.0;
+pub const ENEMY_MAX_HEALTH: f32 = 50.0;
+pub const PUNCH_DAMAGE: f32 = 10.0;
+pub const ATTACK_RANGE: f32 = 1.5;
+pub const IFRAME_DURATION: f32 = 0.5;
+pub const KNOCKBACK_SPEED: f32 = 4.0;
+pub const RESPAWN_DELAY: f32 = 3.0;
+pub const DESPAWN_DELAY: f32 = 2.0;
+
+/// Window, in seconds since entering `Punching`, during which the fist is
+/// actually extended and a hit can land.
+const PUNCH_ACTIVE_START: f32 = 0.15;
+const PUNCH_ACTIVE_END: f32 = 0.35;
+
+/// How narrow the attacker's forward cone needs to be to count as "facing"
+/// the victim (cosine of the half-angle).
+const FACING_DOT_THRESHOLD: f32 = 0.5;
+
+#[derive(Component)]
+pub struct Health {
+    pub current: f32,
+    pub max: f32,
+}
+
+impl Health {
+    pub fn full(max: f32) -> Self {
+        Self { current: max, max }
+    }
+}
+
+/// Combat timing shared by `Player` and `Enemy`: how long the current
+/// `PlayerState` has been active, whether the current punch already landed,
+/// and any remaining invulnerability after taking a hit.
+#[derive(Component, Default)]
+pub struct Combat {
+    pub state_timer: f32,
+    pub punch_landed: bool,
+    pub iframe_timer: f32,
+}
+
+#[derive(Event)]
+pub struct DamageEvent {
+    pub target: Entity,
+    pub amount: f32,
+    /// Horizontal, world-space direction to knock the target away in.
+    pub knockback: Vec3,
+}
+
+/// Advances every combatant's state timer and iframe cooldown.
+pub fn tick_combat_timers(time: Res<Time>, mut combatants: Query<&mut Combat>) {
+    for mut combat in &mut combatants {
+        combat.state_timer += time.delta_seconds();
+        combat.iframe_timer = (combat.iframe_timer - time.delta_seconds()).max(0.0);
+    }
+}
+
+fn is_punch_active(combat: &Combat) -> bool {
+    combat.state_timer >= PUNCH_ACTIVE_START && combat.state_timer <= PUNCH_ACTIVE_END
+}
+
+fn is_facing_and_in_range(attacker: &Transform, victim: &Transform) -> bool {
+    let to_victim = victim.translation - attacker.translation;
+    let distance = to_victim.length();
+    if distance > ATTACK_RANGE || distance <= f32::EPSILON {
+        return false;
+    }
+    let forward = Vec3::from(attacker.forward());
+    forward.dot(to_victim.normalize()) > FACING_DOT_THRESHOLD
+}
+
+/// Lands the player's punch on the enemy once per swing, during the active
+/// frame window, when in range and facing it.
+pub fn player_punch_system(
+    mut player_query: Query<(&Transform, &Player, &mut Combat), Without<Enemy>>,
+    enemy_query: Query<(Entity, &Transform, &Combat), Without<Player>>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut game_audio: EventWriter<GameAudio>,
+) {
+    for (attacker_transform, player, mut combat) in &mut player_query {
+        if player.state != PlayerState::Punching || combat.punch_landed || !is_punch_active(&combat) {
+            continue;
+        }
+        for (enemy_entity, enemy_transform, enemy_combat) in &enemy_query {
+            if enemy_combat.iframe_timer > 0.0 {
+                continue;
+            }
+            if !is_facing_and_in_range(attacker_transform, enemy_transform) {
+                continue;
+            }
+            let mut knockback = enemy_transform.translation - attacker_transform.translation;
+            knockback.y = 0.0;
+            damage_events.send(DamageEvent {
+                target: enemy_entity,
+                amount: PUNCH_DAMAGE,
+                knockback: knockback.normalize_or_zero(),
+            });
+            game_audio.send(GameAudio::Hit { position: enemy_transform.translation });
+            combat.punch_landed = true;
+            break;
+        }
+    }
+}
+
+/// Lands the enemy's punch on the player, mirroring `player_punch_system`.
+pub fn enemy_punch_system(
+    mut enemy_query: Query<(&Transform, &Enemy, &mut Combat), Without<Player>>,
+    player_query: Query<(Entity, &Transform, &Combat), Without<Enemy>>,
+    mut damage_events: EventWriter<DamageEvent>,
+    mut game_audio: EventWriter<GameAudio>,
+) {
+    for (attacker_transform, enemy, mut combat) in &mut enemy_query {
+        if enemy.state != PlayerState::Punching || combat.punch_landed || !is_punch_active(&combat) {
+            continue;
+        }
+        for (player_entity, player_transform, player_combat) in &player_query {
+            if player_combat.iframe_timer > 0.0 {
+                continue;
+            }
+            // `enemy_behavior` turns the enemy's local +Z toward its target,
+            // so flip it half a turn before the shared `forward()` check.
+            let facing = attacker_transform
+                .with_rotation(attacker_transform.rotation * Quat::from_rotation_y(std::f32::consts::PI));
+            if !is_facing_and_in_range(&facing, player_transform) {
+                continue;
+            }
+            let mut knockback = player_transform.translation - attacker_transform.translation;
+            knockback.y = 0.0;
+            damage_events.send(DamageEvent {
+                target: player_entity,
+                amount: PUNCH_DAMAGE,
+                knockback: knockback.normalize_or_zero(),
+            });
+            game_audio.send(GameAudio::Hit { position: player_transform.translation });
+            combat.punch_landed = true;
+            break;
+        }
+    }
+}
+
+/// Applies queued `DamageEvent`s: reduces health, starts i-frames, imparts
+/// knockback, and transitions to `Dead` once health runs out.
+pub fn apply_damage_system(
+    mut damage_events: EventReader<DamageEvent>,
+    mut player_query: Query<
+        (&mut Health, &mut Combat, &mut Player, &mut LinearVelocity),
+        Without<Enemy>,
+    >,
+    mut enemy_query: Query<
+        (&mut Health, &mut Combat, &mut Enemy, &mut LinearVelocity),
+        Without<Player>,
+    >,
+) {
+    for event in damage_events.read() {
+        if let Ok((mut health, mut combat, mut player, mut velocity)) =
+            player_query.get_mut(event.target)
+        {
+            health.current = (health.current - event.amount).max(0.0);
+            combat.iframe_timer = IFRAME_DURATION;
+            velocity.x += event.knockback.x * KNOCKBACK_SPEED;
+            velocity.z += event.knockback.z * KNOCKBACK_SPEED;
+            if health.current <= 0.0 && player.state != PlayerState::Dead {
+                player.state = PlayerState::Dead;
+                combat.state_timer = 0.0;
+            }
+            continue;
+        }
+        if let Ok((mut health, mut combat, mut enemy, mut velocity)) =
+            enemy_query.get_mut(event.target)
+        {
+            health.current = (health.current - event.amount).max(0.0);
+            combat.iframe_timer = IFRAME_DURATION;
+            velocity.x += event.knockback.x * KNOCKBACK_SPEED;
+            velocity.z += event.knockback.z * KNOCKBACK_SPEED;
+            if health.current <= 0.0 && enemy.state != PlayerState::Dead {
+                enemy.state = PlayerState::Dead;
+                combat.state_timer = 0.0;
+            }
+        }
+    }
+}
+
+/// Respawns a dead player at its spawn point after `RESPAWN_DELAY`, and
+/// despawns a dead enemy after `DESPAWN_DELAY`.
+pub fn death_timer_system(
+    mut commands: Commands,
+    mut player_query: Query<
+        (&mut Transform, &mut Player, &mut Health, &mut Combat, &mut LinearVelocity),
+        Without<Enemy>,
+    >,
+    enemy_query: Query<(Entity, &Enemy, &Combat), Without<Player>>,
+) {
+    for (mut transform, mut player, mut health, mut combat, mut velocity) in &mut player_query {
+        if player.state != PlayerState::Dead || combat.state_timer < RESPAWN_DELAY {
+            continue;
+        }
+        transform.translation = player.spawn_point;
+        health.current = health.max;
+        player.state = PlayerState::Idle;
+        combat.state_timer = 0.0;
+        combat.punch_landed = false;
+        velocity.x = 0.0;
+        velocity.y = 0.0;
+        velocity.z = 0.0;
+    }
+
+    for (entity, enemy, combat) in &enemy_query {
+        if enemy.state == PlayerState::Dead && combat.state_timer >= DESPAWN_DELAY {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+use crate::multiplayer::PlayerId;
+use crate::MobileInput;
+
+/// Logical actions, resolved against bindings instead of raw key/button codes.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Action {
+    MoveForward,
+    MoveBack,
+    TurnLeft,
+    TurnRight,
+    Jump,
+    Punch,
+}
+
+/// A single concrete binding an `Action` can be triggered by.
+#[derive(Clone, Copy, Debug)]
+pub enum Source {
+    Key(KeyCode),
+    GamepadButton(GamepadButtonType),
+    GamepadAxisPositive(GamepadAxisType),
+    GamepadAxisNegative(GamepadAxisType),
+}
+
+/// One local player's bindings, attached per-entity so split-screen players
+/// can carry independent layouts and gamepads.
+#[derive(Component)]
+pub struct InputMap {
+    bindings: HashMap<Action, Vec<Source>>,
+    // `None` means gamepad sources read the first connected gamepad.
+    gamepad: Option<Gamepad>,
+}
+
+impl InputMap {
+    /// WASD + Space/Enter.
+    pub fn player_one() -> Self {
+        let mut bindings = HashMap::new();
+        bindings.insert(Action::MoveForward, vec![Source::Key(KeyCode::KeyW)]);
+        bindings.insert(Action::MoveBack, vec![Source::Key(KeyCode::KeyS)]);
+        bindings.insert(Action::TurnLeft, vec![Source::Key(KeyCode::KeyA)]);
+        bindings.insert(Action::TurnRight, vec![Source::Key(KeyCode::KeyD)]);
+        bindings.insert(
+            Action::Jump,
+            vec![
+                Source::Key(KeyCode::Space),
+                Source::GamepadButton(GamepadButtonType::South),
+            ],
+        );
+        bindings.insert(
+            Action::Punch,
+            vec![
+                Source::Key(KeyCode::Enter),
+                Source::GamepadButton(GamepadButtonType::West),
+            ],
+        );
+        Self {
+            bindings,
+            gamepad: None,
+        }
+    }
+
+    /// Arrow keys + RShift/RCtrl.
+    pub fn player_two() -> Self {
+        let mut bindings = HashMap::new();
+        bindings.insert(Action::MoveForward, vec![Source::Key(KeyCode::ArrowUp)]);
+        bindings.insert(Action::MoveBack, vec![Source::Key(KeyCode::ArrowDown)]);
+        bindings.insert(Action::TurnLeft, vec![Source::Key(KeyCode::ArrowLeft)]);
+        bindings.insert(Action::TurnRight, vec![Source::Key(KeyCode::ArrowRight)]);
+        bindings.insert(
+            Action::Jump,
+            vec![
+                Source::Key(KeyCode::ControlRight),
+                Source::GamepadButton(GamepadButtonType::South),
+            ],
+        );
+        bindings.insert(
+            Action::Punch,
+            vec![
+                Source::Key(KeyCode::ShiftRight),
+                Source::GamepadButton(GamepadButtonType::West),
+            ],
+        );
+        Self {
+            bindings,
+            gamepad: None,
+        }
+    }
+
+    /// Binds this profile to a specific gamepad instead of the first one found.
+    pub fn with_gamepad(mut self, gamepad: Gamepad) -> Self {
+        self.gamepad = Some(gamepad);
+        self
+    }
+
+    /// Rebinds `action` to `sources`.
+    pub fn rebind(&mut self, action: Action, sources: Vec<Source>) {
+        self.bindings.insert(action, sources);
+    }
+
+    fn sources(&self, action: Action) -> &[Source] {
+        self.bindings.get(&action).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Per-frame snapshot of one player's logical input state.
+#[derive(Component, Default)]
+pub struct ActionState {
+    pub move_direction: Vec2,
+    pub jump: bool,
+    pub punch: bool,
+}
+
+const GAMEPAD_AXIS_DEADZONE: f32 = 0.15;
+
+/// Resolves each player's `InputMap` against keyboard, gamepad, and
+/// `MobileInput` (which always drives player one) into its `ActionState`.
+pub fn update_action_state(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    gamepad_buttons: Res<ButtonInput<GamepadButton>>,
+    gamepad_axes: Res<Axis<GamepadAxis>>,
+    gamepads: Res<Gamepads>,
+    mobile_input: Res<MobileInput>,
+    mut players: Query<(&InputMap, &mut ActionState, Option<&PlayerId>)>,
+) {
+    for (input_map, mut action_state, player_id) in &mut players {
+        let bound_gamepad = input_map.gamepad.or_else(|| gamepads.iter().next());
+        let is_mobile_driven = player_id.map_or(true, |id| id.0 == 0);
+
+        let pressed = |source: &Source| -> bool {
+            match *source {
+                Source::Key(key) => keyboard_input.pressed(key),
+                Source::GamepadButton(button_type) => bound_gamepad
+                    .is_some_and(|pad| gamepad_buttons.pressed(GamepadButton::new(pad, button_type))),
+                Source::GamepadAxisPositive(axis_type) => bound_gamepad.is_some_and(|pad| {
+                    gamepad_axes
+                        .get(GamepadAxis::new(pad, axis_type))
+                        .is_some_and(|value| value > GAMEPAD_AXIS_DEADZONE)
+                }),
+                Source::GamepadAxisNegative(axis_type) => bound_gamepad.is_some_and(|pad| {
+                    gamepad_axes
+                        .get(GamepadAxis::new(pad, axis_type))
+                        .is_some_and(|value| value < -GAMEPAD_AXIS_DEADZONE)
+                }),
+            }
+        };
+
+        let just_pressed = |source: &Source| -> bool {
+            match *source {
+                Source::Key(key) => keyboard_input.just_pressed(key),
+                Source::GamepadButton(button_type) => bound_gamepad.is_some_and(|pad| {
+                    gamepad_buttons.just_pressed(GamepadButton::new(pad, button_type))
+                }),
+                _ => false,
+            }
+        };
+
+        let is_active = |action: Action| input_map.sources(action).iter().any(pressed);
+        let was_activated = |action: Action| input_map.sources(action).iter().any(just_pressed);
+
+        let mut direction = Vec2::ZERO;
+        if is_active(Action::MoveForward) {
+            direction.y -= 1.0;
+        }
+        if is_active(Action::MoveBack) {
+            direction.y += 1.0;
+        }
+        if is_active(Action::TurnLeft) {
+            direction.x -= 1.0;
+        }
+        if is_active(Action::TurnRight) {
+            direction.x += 1.0;
+        }
+
+        // The bound gamepad's stick feeds the same directional channel as
+        // the digital keyboard bindings above.
+        if let Some(pad) = bound_gamepad {
+            let stick_x = gamepad_axes
+                .get(GamepadAxis::new(pad, GamepadAxisType::LeftStickX))
+                .unwrap_or(0.0);
+            let stick_y = gamepad_axes
+                .get(GamepadAxis::new(pad, GamepadAxisType::LeftStickY))
+                .unwrap_or(0.0);
+            if stick_x.abs() > GAMEPAD_AXIS_DEADZONE {
+                direction.x += stick_x;
+            }
+            if stick_y.abs() > GAMEPAD_AXIS_DEADZONE {
+                direction.y -= stick_y;
+            }
+        }
+
+        if is_mobile_driven
+            && (mobile_input.joystick_x != 0.0 || mobile_input.joystick_y != 0.0)
+        {
+            direction.x += mobile_input.joystick_x;
+            direction.y += mobile_input.joystick_y;
+        }
+
+        action_state.move_direction = direction;
+        action_state.jump = was_activated(Action::Jump) || (is_mobile_driven && mobile_input.jump);
+        action_state.punch =
+            was_activated(Action::Punch) || (is_mobile_driven && mobile_input.punch);
+    }
+}
@@ -1,6 +1,25 @@
 use bevy::prelude::*;
+use bevy_xpbd_3d::prelude::*;
 use wasm_bindgen::prelude::*;
 
+mod animation;
+mod audio;
+mod combat;
+mod input;
+mod multiplayer;
+mod physics;
+
+use animation::AnimationController;
+use audio::{audio_system, Footsteps, GameAudio};
+use bevy::audio::SpatialListener;
+use combat::{
+    apply_damage_system, death_timer_system, enemy_punch_system, player_punch_system,
+    tick_combat_timers, Combat, DamageEvent, Health, ENEMY_MAX_HEALTH, PLAYER_MAX_HEALTH,
+};
+use input::{update_action_state, ActionState, InputMap};
+use multiplayer::{nearest_player, sync_split_screen_viewports, MultiplayerConfig, PlayerId};
+use physics::{character_collider, ground_caster, CharacterPhysicsPlugin, Ground, JUMP_SPEED};
+
 #[wasm_bindgen]
 pub fn start() {
     std::panic::set_hook(Box::new(console_error_panic_hook::hook));
@@ -18,10 +37,35 @@ pub fn start() {
             meta_check: bevy::asset::AssetMetaCheck::Never,
             ..default()
         }))
+        .add_plugins(CharacterPhysicsPlugin)
         .insert_resource(ClearColor(Color::srgb_u8(135, 206, 235))) // 0x87ceeb Sky Blue
         .init_resource::<MobileInput>()
+        .insert_resource(MultiplayerConfig::from_requested_mode())
+        .add_event::<DamageEvent>()
+        .add_event::<GameAudio>()
         .add_systems(Startup, setup)
-        .add_systems(Update, (animate_light_direction, sync_mobile_input, player_movement, enemy_behavior, link_animations, animate_fps_hands))
+        .add_systems(
+            Update,
+            (
+                animate_light_direction,
+                sync_mobile_input,
+                update_action_state,
+                player_movement,
+                enemy_behavior,
+                tick_combat_timers,
+                player_punch_system,
+                enemy_punch_system,
+                apply_damage_system,
+                death_timer_system,
+                player_footstep_audio,
+                enemy_footstep_audio,
+                audio_system,
+                link_animations,
+                animate_fps_hands,
+                sync_split_screen_viewports,
+            )
+                .chain(),
+        )
         .run();
 }
 
@@ -29,6 +73,7 @@ pub fn start() {
 struct FpsHand {
     side: HandSide,
     original_position: Vec3,
+    owner: PlayerId,
 }
 
 enum HandSide {
@@ -42,6 +87,8 @@ struct Player {
     speed: f32,
     state: PlayerState,
     animation_entity: Option<Entity>,
+    grounded: bool,
+    spawn_point: Vec3,
 }
 
 #[derive(Component)]
@@ -49,6 +96,7 @@ struct Enemy {
     speed: f32,
     state: PlayerState,
     animation_entity: Option<Entity>,
+    grounded: bool,
 }
 
 #[derive(Default, PartialEq, Eq, Clone, Copy, Debug)]
@@ -58,6 +106,7 @@ enum PlayerState {
     Running,
     Jumping,
     Punching,
+    Dead,
 }
 
 #[derive(Resource, Default)]
@@ -73,6 +122,10 @@ struct MobileInput {
 struct AudioAssets {
     bgm: Handle<AudioSource>,
     punch: Handle<AudioSource>,
+    footstep: Handle<AudioSource>,
+    jump: Handle<AudioSource>,
+    hit: Handle<AudioSource>,
+    land: Handle<AudioSource>,
 }
 
 use std::sync::Mutex;
@@ -107,6 +160,7 @@ struct Animations {
     run: AnimationNodeIndex,
     punch: AnimationNodeIndex,
     jump: AnimationNodeIndex,
+    fall: AnimationNodeIndex,
 }
 
 fn setup(
@@ -115,6 +169,7 @@ fn setup(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut graphs: ResMut<Assets<AnimationGraph>>,
+    multiplayer: Res<MultiplayerConfig>,
 ) {
     // Light
     commands.insert_resource(AmbientLight {
@@ -133,24 +188,37 @@ fn setup(
     });
 
     // Ground
-    commands.spawn(PbrBundle {
-        mesh: meshes.add(Plane3d::default().mesh().size(100.0, 100.0)),
-        material: materials.add(StandardMaterial {
-            base_color: Color::srgb_u8(61, 145, 64),
-            perceptual_roughness: 0.8,
+    commands.spawn((
+        PbrBundle {
+            mesh: meshes.add(Plane3d::default().mesh().size(100.0, 100.0)),
+            material: materials.add(StandardMaterial {
+                base_color: Color::srgb_u8(61, 145, 64),
+                perceptual_roughness: 0.8,
+                ..default()
+            }),
+            transform: Transform::from_rotation(Quat::from_rotation_x(0.0)),
             ..default()
-        }),
-        transform: Transform::from_rotation(Quat::from_rotation_x(0.0)),
-        ..default()
-    });
+        },
+        RigidBody::Static,
+        Collider::half_space(Vec3::Y),
+        Ground,
+    ));
 
     // Audio
     let bgm = asset_server.load("sounds/music.mp3");
     let punch_sound = asset_server.load("sounds/punch.mp3");
+    let footstep_sound = asset_server.load("sounds/footstep.mp3");
+    let jump_sound = asset_server.load("sounds/jump.mp3");
+    let hit_sound = asset_server.load("sounds/hit.mp3");
+    let land_sound = asset_server.load("sounds/land.mp3");
 
     commands.insert_resource(AudioAssets {
         bgm: bgm.clone(),
         punch: punch_sound,
+        footstep: footstep_sound,
+        jump: jump_sound,
+        hit: hit_sound,
+        land: land_sound,
     });
 
     commands.spawn(AudioBundle {
@@ -164,6 +232,7 @@ fn setup(
     let run = graph.add_clip(asset_server.load("models/running.glb#Animation0"), 1.0, graph.root);
     let punch = graph.add_clip(asset_server.load("models/punching.glb#Animation0"), 1.0, graph.root);
     let jump = graph.add_clip(asset_server.load("models/jump.glb#Animation0"), 1.0, graph.root);
+    let fall = graph.add_clip(asset_server.load("models/fall.glb#Animation0"), 1.0, graph.root);
 
     let graph_handle = graphs.add(graph);
 
@@ -173,64 +242,146 @@ fn setup(
         run,
         punch,
         jump,
+        fall,
     });
 
-    // Player
-    commands.spawn((
-        SceneBundle {
-            scene: asset_server.load("models/character.glb#Scene0"),
-            transform: Transform::from_xyz(0.0, 0.0, 0.0),
-            ..default()
-        },
-        Player { 
-            speed: 5.0,
-            state: PlayerState::Idle,
-            animation_entity: None, 
-        },
-    )).with_children(|parent| {
-        // FPS Camera
-        parent.spawn(Camera3dBundle {
-            transform: Transform::from_xyz(0.0, 1.6, 0.2).looking_at(Vec3::new(0.0, 1.6, -1.0), Vec3::Y),
-            ..default()
-        }).with_children(|camera| {
-             // Left Hand
-            camera.spawn((
-                PbrBundle {
-                    mesh: meshes.add(Cuboid::new(0.1, 0.1, 0.25)),
-                    material: materials.add(Color::srgb(0.8, 0.1, 0.1)),
-                    transform: Transform::from_xyz(-0.25, -0.2, -0.4),
-                    ..default()
-                },
-                FpsHand { side: HandSide::Left, original_position: Vec3::new(-0.25, -0.2, -0.4) }
-            ));
-            // Right Hand
-            camera.spawn((
-                PbrBundle {
-                    mesh: meshes.add(Cuboid::new(0.1, 0.1, 0.25)),
-                    material: materials.add(Color::srgb(0.8, 0.1, 0.1)),
-                    transform: Transform::from_xyz(0.25, -0.2, -0.4),
-                    ..default()
-                },
-                FpsHand { side: HandSide::Right, original_position: Vec3::new(0.25, -0.2, -0.4) }
-            ));
-        });
-    });
+    // Player one always exists; player two is spawned alongside it when
+    // split-screen multiplayer is turned on.
+    spawn_player(
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        &asset_server,
+        PlayerId(0),
+        Transform::from_xyz(0.0, 0.9, 0.0),
+        InputMap::player_one().with_gamepad(Gamepad::new(0)),
+    );
+
+    if multiplayer.two_player {
+        spawn_player(
+            &mut commands,
+            &mut meshes,
+            &mut materials,
+            &asset_server,
+            PlayerId(1),
+            Transform::from_xyz(2.0, 0.9, 0.0),
+            InputMap::player_two().with_gamepad(Gamepad::new(1)),
+        );
+    }
 
     // Enemy
     commands.spawn((
         SceneBundle {
             scene: asset_server.load("models/character.glb#Scene0"),
-            transform: Transform::from_xyz(5.0, 0.0, -5.0).with_rotation(Quat::from_rotation_y(3.14)), // Face player roughly
+            transform: Transform::from_xyz(5.0, 0.9, -5.0).with_rotation(Quat::from_rotation_y(3.14)), // Face player roughly
             ..default()
         },
-        Enemy { 
+        Enemy {
             speed: 3.5,
             state: PlayerState::Idle,
-            animation_entity: None, 
+            animation_entity: None,
+            grounded: false,
         },
+        Health::full(ENEMY_MAX_HEALTH),
+        Combat::default(),
+        Footsteps::default(),
+        AnimationController::default(),
+        RigidBody::Dynamic,
+        character_collider(),
+        LockedAxes::new().lock_rotation_x().lock_rotation_z(),
+        ground_caster(),
+        CollidingEntities::default(),
     ));
 }
 
+/// Spawns one local player: body, FPS camera, and its pair of hands, all
+/// tagged with `id` so input routing and split-screen viewports know which
+/// player they belong to.
+fn spawn_player(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    asset_server: &AssetServer,
+    id: PlayerId,
+    transform: Transform,
+    input_map: InputMap,
+) {
+    commands
+        .spawn((
+            SceneBundle {
+                scene: asset_server.load("models/character.glb#Scene0"),
+                transform,
+                ..default()
+            },
+            Player {
+                speed: 5.0,
+                state: PlayerState::Idle,
+                animation_entity: None,
+                grounded: false,
+                spawn_point: transform.translation,
+            },
+            id,
+            input_map,
+            ActionState::default(),
+            Health::full(PLAYER_MAX_HEALTH),
+            Combat::default(),
+            Footsteps::default(),
+            AnimationController::default(),
+            RigidBody::Dynamic,
+            character_collider(),
+            LockedAxes::new().lock_rotation_x().lock_rotation_z(),
+            ground_caster(),
+            CollidingEntities::default(),
+        ))
+        .with_children(|parent| {
+            // FPS Camera. Only player one gets a SpatialListener: Bevy
+            // resolves a single listener per frame, so a second one in
+            // split-screen would leave panning undefined for both players.
+            let mut camera = parent.spawn((
+                Camera3dBundle {
+                    transform: Transform::from_xyz(0.0, 1.6, 0.2)
+                        .looking_at(Vec3::new(0.0, 1.6, -1.0), Vec3::Y),
+                    ..default()
+                },
+                id,
+            ));
+            if id == PlayerId(0) {
+                camera.insert(SpatialListener::new(0.3));
+            }
+            camera
+                .with_children(|camera| {
+                    // Left Hand
+                    camera.spawn((
+                        PbrBundle {
+                            mesh: meshes.add(Cuboid::new(0.1, 0.1, 0.25)),
+                            material: materials.add(Color::srgb(0.8, 0.1, 0.1)),
+                            transform: Transform::from_xyz(-0.25, -0.2, -0.4),
+                            ..default()
+                        },
+                        FpsHand {
+                            side: HandSide::Left,
+                            original_position: Vec3::new(-0.25, -0.2, -0.4),
+                            owner: id,
+                        },
+                    ));
+                    // Right Hand
+                    camera.spawn((
+                        PbrBundle {
+                            mesh: meshes.add(Cuboid::new(0.1, 0.1, 0.25)),
+                            material: materials.add(Color::srgb(0.8, 0.1, 0.1)),
+                            transform: Transform::from_xyz(0.25, -0.2, -0.4),
+                            ..default()
+                        },
+                        FpsHand {
+                            side: HandSide::Right,
+                            original_position: Vec3::new(0.25, -0.2, -0.4),
+                            owner: id,
+                        },
+                    ));
+                });
+        });
+}
+
 fn animate_light_direction(
     _time: Res<Time>,
     mut _query: Query<&mut Transform, With<DirectionalLight>>,
@@ -275,24 +426,44 @@ fn link_animations(
 
 fn enemy_behavior(
     time: Res<Time>,
-    mut enemy_query: Query<(&mut Transform, &mut Enemy), Without<Player>>,
-    player_query: Query<&Transform, With<Player>>,
+    mut enemy_query: Query<
+        (&mut Transform, &mut Enemy, &mut Combat, &mut AnimationController, &CollidingEntities),
+        Without<Player>,
+    >,
+    player_query: Query<(Entity, &Transform), With<Player>>,
     mut animation_players: Query<&mut AnimationPlayer>,
     animations: Res<Animations>,
 ) {
-    let player_transform = if let Ok(t) = player_query.get_single() {
-        t
-    } else {
+    if player_query.is_empty() {
         return;
-    };
+    }
+
+    for (mut transform, mut enemy, mut combat, mut controller, colliding_entities) in &mut enemy_query {
+        if enemy.state == PlayerState::Dead {
+            if let Some(entity) = enemy.animation_entity {
+                if let Ok(mut anim) = animation_players.get_mut(entity) {
+                    controller.apply(&mut anim, &animations, enemy.state, time.delta_seconds());
+                }
+            }
+            continue;
+        }
 
-    for (mut transform, mut enemy) in &mut enemy_query {
+        // Chase whichever local player is closest; in split-screen that may
+        // not be player one.
+        let Some(player_transform) =
+            nearest_player(transform.translation, player_query.iter().map(|(_, t)| t))
+        else {
+            continue;
+        };
         let distance = transform.translation.distance(player_transform.translation);
-        
+
         let chase_range = 15.0;
-        let attack_range = 1.5;
+        let in_contact_with_player = player_query
+            .iter()
+            .any(|(entity, _)| colliding_entities.contains(&entity));
 
         // State Transition
+        let previous_state = enemy.state;
         if enemy.state == PlayerState::Punching {
              if let Some(entity) = enemy.animation_entity {
                 if let Ok(anim) = animation_players.get(entity) {
@@ -302,7 +473,7 @@ fn enemy_behavior(
                 }
              }
         } else {
-            if distance < attack_range {
+            if in_contact_with_player {
                 enemy.state = PlayerState::Punching;
             } else if distance < chase_range {
                 enemy.state = PlayerState::Running;
@@ -310,6 +481,10 @@ fn enemy_behavior(
                 enemy.state = PlayerState::Idle;
             }
         }
+        if enemy.state != previous_state {
+            combat.state_timer = 0.0;
+            combat.punch_landed = false;
+        }
 
         // Logic
         match enemy.state {
@@ -330,75 +505,45 @@ fn enemy_behavior(
         // Animation
         if let Some(entity) = enemy.animation_entity {
             if let Ok(mut enemy_anim) = animation_players.get_mut(entity) {
-                match enemy.state {
-                    PlayerState::Running => {
-                         if !enemy_anim.is_playing_animation(animations.run) {
-                             enemy_anim.play(animations.run).repeat().set_speed(1.5);
-                        }
-                    }
-                    PlayerState::Idle => {
-                         if !enemy_anim.is_playing_animation(animations.idle) {
-                             enemy_anim.play(animations.idle).repeat().set_speed(1.0);
-                         }
-                    }
-                    PlayerState::Punching => {
-                         if !enemy_anim.is_playing_animation(animations.punch) {
-                             enemy_anim.play(animations.punch).set_speed(1.0);
-                         } 
-                    }
-                    PlayerState::Jumping => {
-                         if !enemy_anim.is_playing_animation(animations.idle) {
-                             enemy_anim.play(animations.idle).repeat().set_speed(1.0);
-                         }
-                    }
-                }
+                controller.apply(&mut enemy_anim, &animations, enemy.state, time.delta_seconds());
             }
         }
     }
 }
 
 fn player_movement(
-    mut commands: Commands,
-    keyboard_input: Res<ButtonInput<KeyCode>>,
-    mobile_input: Res<MobileInput>,
     time: Res<Time>,
-    mut query: Query<(&mut Transform, &mut Player)>, 
+    mut query: Query<(
+        &mut Transform,
+        &mut Player,
+        &mut Combat,
+        &mut AnimationController,
+        &mut LinearVelocity,
+        &ActionState,
+    )>,
     mut animation_players: Query<&mut AnimationPlayer>,
     animations: Res<Animations>,
-    audio_assets: Res<AudioAssets>,
+    mut game_audio: EventWriter<GameAudio>,
 ) {
-    for (mut transform, mut player) in &mut query {
-        let mut direction = Vec3::ZERO;
-
-        // Input handling (Keyboard)
-        if keyboard_input.pressed(KeyCode::KeyW) { direction.z -= 1.0; }
-        if keyboard_input.pressed(KeyCode::KeyS) { direction.z += 1.0; }
-        if keyboard_input.pressed(KeyCode::KeyA) { direction.x -= 1.0; }
-        if keyboard_input.pressed(KeyCode::KeyD) { direction.x += 1.0; }
-        
-        // Input handling (Mobile)
-        if mobile_input.joystick_x != 0.0 || mobile_input.joystick_y != 0.0 {
-             direction.x += mobile_input.joystick_x;
-             direction.z += mobile_input.joystick_y;
-        }
+    for (mut transform, mut player, mut combat, mut controller, mut velocity, action_state) in &mut query {
+        let direction = Vec3::new(action_state.move_direction.x, 0.0, action_state.move_direction.y);
 
-        let jump = keyboard_input.just_pressed(KeyCode::Space) || mobile_input.jump;
-        let punch = keyboard_input.just_pressed(KeyCode::Enter) || mobile_input.punch;
+        let jump = action_state.jump;
+        let punch = action_state.punch;
 
         // State Machine & Physics Logic
+        let previous_state = player.state;
         match player.state {
             PlayerState::Idle | PlayerState::Running => {
                 let is_moving = direction.length_squared() > 0.0;
-                
+
                 if punch {
                    player.state = PlayerState::Punching;
-                   // Play Punch Sound
-                   commands.spawn(AudioBundle {
-                       source: audio_assets.punch.clone(),
-                       settings: PlaybackSettings::DESPAWN,
-                   });
-                } else if jump {
+                   game_audio.send(GameAudio::Punch { position: transform.translation });
+                } else if jump && player.grounded {
                    player.state = PlayerState::Jumping;
+                   velocity.y = JUMP_SPEED;
+                   game_audio.send(GameAudio::Jump { position: transform.translation });
                 } else if is_moving {
                    player.state = PlayerState::Running;
                 } else {
@@ -406,23 +551,35 @@ fn player_movement(
                 }
             },
              PlayerState::Jumping => {
-                 // in jump state
+                 // Land once the ground check reports contact again.
+                 if player.grounded {
+                     player.state = PlayerState::Idle;
+                     game_audio.send(GameAudio::Land { position: transform.translation });
+                 }
             },
             PlayerState::Punching => {
                 // in punch state
             }
+            PlayerState::Dead => {
+                // Movement and input are disabled until death_timer_system
+                // respawns the player.
+            }
+        }
+        if player.state != previous_state {
+            combat.state_timer = 0.0;
+            combat.punch_landed = false;
         }
 
         // Tank Controls / FPS Steering
         // Rotate (Yaw)
         let rotation_speed = 2.0;
         let rotation_input = -direction.x; // A/D or Joystick X
-        if rotation_input.abs() > 0.0 {
+        if player.state != PlayerState::Dead && rotation_input.abs() > 0.0 {
              transform.rotate_y(rotation_input * rotation_speed * time.delta_seconds());
         }
 
         // Move (Forward/Back)
-        if player.state != PlayerState::Punching {
+        if !matches!(player.state, PlayerState::Punching | PlayerState::Dead) {
              let move_input = -direction.z; // W/S or Joystick Y. direction.z is -1 for W.
              if move_input.abs() > 0.0 {
                  let forward = transform.forward();
@@ -434,32 +591,12 @@ fn player_movement(
         // Animation Application
         if let Some(entity) = player.animation_entity {
             if let Ok(mut player_anim) = animation_players.get_mut(entity) {
-                match player.state {
-                    PlayerState::Running => {
-                         if !player_anim.is_playing_animation(animations.run) {
-                             player_anim.play(animations.run).repeat().set_speed(1.5);
-                        }
-                    }
-                    PlayerState::Idle => {
-                         if !player_anim.is_playing_animation(animations.idle) {
-                             player_anim.play(animations.idle).repeat().set_speed(1.0);
-                         }
-                    }
-                    PlayerState::Punching => {
-                         if !player_anim.is_playing_animation(animations.punch) {
-                             player_anim.play(animations.punch).set_speed(1.0);
-                         } else if player_anim.all_finished() {
-                             player.state = PlayerState::Idle;
-                         }
-                    }
-                    PlayerState::Jumping => {
-                        if !player_anim.is_playing_animation(animations.jump) {
-                             player_anim.play(animations.jump).set_speed(1.0);
-                        } else if player_anim.all_finished() {
-                             player.state = PlayerState::Idle;
-                         }
-                    }
+                if player.state == PlayerState::Punching && player_anim.all_finished() {
+                    player.state = PlayerState::Idle;
+                    combat.state_timer = 0.0;
+                    combat.punch_landed = false;
                 }
+                controller.apply(&mut player_anim, &animations, player.state, time.delta_seconds());
             }
         }
     }
@@ -468,48 +605,80 @@ fn player_movement(
 fn animate_fps_hands(
     time: Res<Time>,
     mut hand_query: Query<(&mut Transform, &FpsHand)>,
-    player_query: Query<&Player>,
+    player_query: Query<(&PlayerId, &Player, &AnimationController)>,
 ) {
-    let player = if let Ok(p) = player_query.get_single() {
-        p
-    } else {
-        return;
-    };
-
     let elapsed = time.elapsed_seconds();
 
     for (mut transform, hand) in &mut hand_query {
+        // Each pair of hands follows its own player's state, not just "the"
+        // player, so split-screen players animate independently.
+        let Some((_, player, controller)) =
+            player_query.iter().find(|(id, _, _)| **id == hand.owner)
+        else {
+            continue;
+        };
         let mut target_pos = hand.original_position;
 
-        match player.state {
-             PlayerState::Running => {
-                 // Bobbing
-                 let bob_speed = 10.0;
-                 let bob_amount = 0.05;
-                 target_pos.y += (elapsed * bob_speed).sin() * bob_amount;
-                 // Alternating
-                 let offset = match hand.side {
-                     HandSide::Left => 0.0,
-                     HandSide::Right => std::f32::consts::PI,
-                 };
-                 target_pos.z += (elapsed * bob_speed + offset).sin() * 0.05;
-             },
-             PlayerState::Punching => {
-                 // Simple Punch animation
-                 // Ideally we'd use valid animation clips, but procedural is okay for placeholders
-                 // Check which hand punches? For now, just RIGHT hand punches for simplicity
-                 if matches!(hand.side, HandSide::Right) {
-                      let punch_speed = 20.0;
-                      target_pos.z -= (elapsed * punch_speed).sin().abs() * 0.3; // Move forward (negative Z)
-                 }
-             },
-             _ => {
-                 // Idle breathing
-                 target_pos.y += (elapsed * 2.0).sin() * 0.01;
-             }
+        if player.state == PlayerState::Punching {
+            // Ideally we'd use a real punch clip, but procedural is fine for
+            // placeholders. Only the right hand throws the punch.
+            if matches!(hand.side, HandSide::Right) {
+                let punch_speed = 20.0;
+                target_pos.z -= (elapsed * punch_speed).sin().abs() * 0.3; // Move forward (negative Z)
+            }
+        } else {
+            // Bob amplitude scales with the controller's blended locomotion
+            // weight, which ramps rather than snaps, so hands settle
+            // smoothly between Running and Idle instead of cutting abruptly.
+            let weight = controller.locomotion_weight;
+            let bob_speed = 10.0;
+            target_pos.y += (elapsed * bob_speed).sin() * 0.05 * weight;
+            let offset = match hand.side {
+                HandSide::Left => 0.0,
+                HandSide::Right => std::f32::consts::PI,
+            };
+            target_pos.z += (elapsed * bob_speed + offset).sin() * 0.05 * weight;
+            // Idle breathing fades in as the locomotion weight fades out.
+            target_pos.y += (elapsed * 2.0).sin() * 0.01 * (1.0 - weight);
         }
 
         // Smoothly interpolate (simple loop-based, not frame-perfect but simple)
         transform.translation = transform.translation.lerp(target_pos, 10.0 * time.delta_seconds());
     }
 }
+
+/// While running, ticks the player's `Footsteps` timer and fires a
+/// `Footstep` cue at its position each time a step lands.
+fn player_footstep_audio(
+    time: Res<Time>,
+    mut query: Query<(&Transform, &Player, &mut Footsteps)>,
+    mut game_audio: EventWriter<GameAudio>,
+) {
+    for (transform, player, mut footsteps) in &mut query {
+        if player.state != PlayerState::Running {
+            footsteps.timer.reset();
+            continue;
+        }
+        if footsteps.timer.tick(time.delta()).just_finished() {
+            game_audio.send(GameAudio::Footstep { position: transform.translation });
+        }
+    }
+}
+
+/// Enemy counterpart of `player_footstep_audio`, so an approaching enemy can
+/// be heard before it's seen.
+fn enemy_footstep_audio(
+    time: Res<Time>,
+    mut query: Query<(&Transform, &Enemy, &mut Footsteps)>,
+    mut game_audio: EventWriter<GameAudio>,
+) {
+    for (transform, enemy, mut footsteps) in &mut query {
+        if enemy.state != PlayerState::Running {
+            footsteps.timer.reset();
+            continue;
+        }
+        if footsteps.timer.tick(time.delta()).just_finished() {
+            game_audio.send(GameAudio::Footstep { position: transform.translation });
+        }
+    }
+}
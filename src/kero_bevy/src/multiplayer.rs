@@ -0,0 +1,91 @@
+use std::sync::{Mutex, OnceLock};
+
+use bevy::prelude::*;
+use bevy::render::camera::Viewport;
+use bevy::window::{PrimaryWindow, WindowResized};
+use wasm_bindgen::prelude::*;
+
+/// Which local player an entity (character, camera, FPS hand) belongs to.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct PlayerId(pub u8);
+
+/// Whether a second, local player is spawned alongside player one.
+#[derive(Resource)]
+pub struct MultiplayerConfig {
+    pub two_player: bool,
+}
+
+impl Default for MultiplayerConfig {
+    fn default() -> Self {
+        Self { two_player: false }
+    }
+}
+
+impl MultiplayerConfig {
+    /// Reads whatever JS last set via `set_two_player_mode`.
+    pub fn from_requested_mode() -> Self {
+        let two_player = two_player_requested_state().lock().map_or(false, |state| *state);
+        Self { two_player }
+    }
+}
+
+static TWO_PLAYER_REQUESTED: OnceLock<Mutex<bool>> = OnceLock::new();
+
+fn two_player_requested_state() -> &'static Mutex<bool> {
+    TWO_PLAYER_REQUESTED.get_or_init(|| Mutex::new(false))
+}
+
+/// Call from JS before `start()` to opt into split-screen two-player mode.
+#[wasm_bindgen]
+pub fn set_two_player_mode(enabled: bool) {
+    if let Ok(mut state) = two_player_requested_state().lock() {
+        *state = enabled;
+    }
+}
+
+/// Splits the primary window's viewport left/right between `PlayerId(0)` and
+/// `PlayerId(1)`, re-running on the first frame and on resize.
+pub fn sync_split_screen_viewports(
+    config: Res<MultiplayerConfig>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut resize_events: EventReader<WindowResized>,
+    mut cameras: Query<(&PlayerId, &mut Camera)>,
+    mut initialized: Local<bool>,
+) {
+    if !config.two_player {
+        return;
+    }
+    if *initialized && resize_events.is_empty() {
+        return;
+    }
+    resize_events.clear();
+    *initialized = true;
+
+    let Ok(window) = windows.get_single() else {
+        return;
+    };
+    let width = window.resolution.physical_width();
+    let height = window.resolution.physical_height();
+    let half_width = width / 2;
+
+    for (player_id, mut camera) in &mut cameras {
+        let physical_position = UVec2::new(if player_id.0 == 0 { 0 } else { half_width }, 0);
+        camera.viewport = Some(Viewport {
+            physical_position,
+            physical_size: UVec2::new(half_width, height),
+            ..default()
+        });
+    }
+}
+
+/// Picks the transform nearest to `from`.
+pub fn nearest_player<'a>(
+    from: Vec3,
+    players: impl Iterator<Item = &'a Transform>,
+) -> Option<&'a Transform> {
+    players.min_by(|a, b| {
+        a.translation
+            .distance_squared(from)
+            .total_cmp(&b.translation.distance_squared(from))
+    })
+}
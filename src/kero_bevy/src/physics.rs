@@ -0,0 +1,62 @@
+use bevy::prelude::*;
+use bevy_xpbd_3d::prelude::*;
+
+use crate::{Enemy, Player};
+
+/// Upward speed imparted on a jump, in meters/second.
+pub const JUMP_SPEED: f32 = 6.0;
+
+/// How far below the capsule's feet we cast to decide "grounded".
+const GROUND_CAST_DISTANCE: f32 = 0.15;
+
+/// Marks the static ground collider so nothing mistakes it for a character.
+#[derive(Component)]
+pub struct Ground;
+
+/// Wires up `bevy_xpbd_3d` and the ground-check system every character needs.
+pub struct CharacterPhysicsPlugin;
+
+impl Plugin for CharacterPhysicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins(PhysicsPlugins::default())
+            .insert_resource(Gravity(Vec3::NEG_Y * 9.81))
+            .add_systems(
+                FixedUpdate,
+                update_grounded.after(PhysicsSet::StepSimulation),
+            );
+    }
+}
+
+/// Capsule collider shared by `Player` and `Enemy` so attack range becomes
+/// real contact instead of a raw distance check. Half-extent is 0.9 (0.5
+/// length + 0.4 radius), so spawn transforms must sit at y = 0.9 for the
+/// capsule to rest on the ground plane instead of straddling it.
+pub fn character_collider() -> Collider {
+    Collider::capsule(1.0, 0.4)
+}
+
+/// Downward shape cast used to detect standing on the ground plane.
+pub fn ground_caster() -> ShapeCaster {
+    ShapeCaster::new(
+        Collider::capsule(0.1, 0.35),
+        Vec3::NEG_Y * 0.9,
+        Quat::IDENTITY,
+        Direction3d::NEG_Y,
+    )
+    .with_max_time_of_impact(GROUND_CAST_DISTANCE)
+    .with_ignore_origin_penetration(true)
+}
+
+/// Updates `Player::grounded` / `Enemy::grounded` from their shape casts so
+/// jump/land transitions can key off real contact with the floor.
+fn update_grounded(
+    mut player_query: Query<(&ShapeHits, &mut Player)>,
+    mut enemy_query: Query<(&ShapeHits, &mut Enemy)>,
+) {
+    for (hits, mut player) in &mut player_query {
+        player.grounded = !hits.is_empty();
+    }
+    for (hits, mut enemy) in &mut enemy_query {
+        enemy.grounded = !hits.is_empty();
+    }
+}